@@ -0,0 +1,92 @@
+#!/usr/bin/env python3
+# -*- coding: utf-8 -*-
+
+'''
+tests for the RepoFixture, RepoFixtureBuilder and TempRepo harness; run as: python3 -m unittest discover tests
+'''
+
+import os
+import sys
+import shutil
+import tempfile
+import unittest
+
+sys.dont_write_bytecode = True
+sys.path.insert(0, os.path.join(os.path.dirname(os.path.abspath(__file__)), '..', 'bin'))
+
+import repo
+
+def diverged():
+    '''
+    diverged: a builder with a feature branch and the home branch each adding a file of their own, so they merge cleanly
+    '''
+    return (repo.RepoFixture.builder().commits(1)
+            .branch('feature').step('create', filepath='src/feature.txt', content='feature').step('commit')
+            .home().step('create', filepath='src/home.txt', content='home').step('commit'))
+
+class TestRepoFixture(unittest.TestCase):
+
+    def test_build_conflict(self):
+        with tempfile.TemporaryDirectory(prefix='repo-test-') as tmpdir:
+            fixture = repo.RepoFixture.builder().commits(2).conflict('content').build(tmpdir)
+            self.assertEqual(fixture.path, os.path.abspath(tmpdir))
+            self.assertEqual(len(fixture.results), 2)
+            fixture.assert_branch_exists(fixture.results[-1]['branch_name'])
+            with self.assertRaises(AssertionError):
+                fixture.assert_branch_exists('no-such-branch')
+            shutil.rmtree(fixture.repo._isolated_home, ignore_errors=True)
+
+    def test_merge_then_assert_conflicted(self):
+        with repo.TempRepo(repo.RepoFixture.builder().commits(1).conflict('content')) as temp:
+            branch = temp.results[-1]['branch_name']
+            with self.assertRaises(AssertionError):
+                temp.assert_conflicted()
+            self.assertNotEqual(temp.merge(branch), 0)
+            temp.assert_conflicted()
+            temp.assert_conflicted(temp.results[-1]['filepath'])
+            with self.assertRaises(AssertionError):
+                temp.assert_conflicted('not/unmerged')
+
+    def test_git_check(self):
+        with repo.TempRepo(repo.RepoFixture.builder().commits(1)) as temp:
+            self.assertTrue(temp.git('rev-parse HEAD'))
+            with self.assertRaises(repo.CalledProcessError):
+                temp.git('rev-parse --verify -q no-such-rev')
+            self.assertEqual(temp.git('rev-parse --verify -q no-such-rev', check=False), '')
+
+    def test_builder_merge(self):
+        with repo.TempRepo(repo.RepoFixture.builder().commits(1).conflict('content').merge()) as temp:
+            self.assertNotEqual(temp.results[-1], 0)
+            temp.assert_conflicted()
+        with repo.TempRepo(diverged().merge('feature', conflict=False)) as temp:
+            self.assertEqual(temp.results[-1], 0)
+            self.assertEqual(temp.git('rev-list --count --merges HEAD'), '1')
+
+    def test_builder_merge_failure(self):
+        with tempfile.TemporaryDirectory(prefix='repo-test-') as tmpdir:
+            builder = repo.RepoFixture.builder().commits(1).conflict('content').merge(conflict=False)
+            with self.assertRaisesRegex(AssertionError, 'expected a clean merge'):
+                builder.build(tmpdir)
+        with tempfile.TemporaryDirectory(prefix='repo-test-') as tmpdir:
+            with self.assertRaisesRegex(AssertionError, 'expected a conflict'):
+                diverged().merge('feature').build(tmpdir)
+
+    def test_temp_repo_close(self):
+        temp = repo.TempRepo(repo.RepoFixture.builder().commits(1))
+        home = temp.repo._isolated_home
+        self.assertTrue(os.path.isdir(temp.path))
+        self.assertTrue(home and os.path.isdir(home))
+        temp.close()
+        self.assertFalse(os.path.exists(temp.path))
+        self.assertFalse(os.path.exists(home))
+
+    def test_temp_repo_keep(self):
+        with repo.TempRepo(repo.RepoFixture.builder().commits(1), keep=True) as temp:
+            pass
+        self.assertTrue(os.path.isdir(temp.path))
+        temp.keep = False
+        temp.close()
+        self.assertFalse(os.path.exists(temp.path))
+
+if __name__ == '__main__':
+    unittest.main()